@@ -1753,7 +1753,7 @@ impl Default for MemoryConfig {
 /// Observability backend configuration (`[observability]` section).
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ObservabilityConfig {
-    /// "none" | "log" | "prometheus" | "otel"
+    /// "none" | "log" | "prometheus" | "otel" | "webhook"
     pub backend: String,
 
     /// OTLP endpoint (e.g. "http://localhost:4318"). Only used when backend = "otel".
@@ -1764,6 +1764,11 @@ pub struct ObservabilityConfig {
     #[serde(default)]
     pub otel_service_name: Option<String>,
 
+    /// HTTP endpoint receiving JSON-encoded events and metrics. Only used when
+    /// backend = "webhook". Overridden by `ZEROCLAW_WEBHOOK_URL`.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+
     /// Runtime trace storage mode: "none" | "rolling" | "full".
     /// Controls whether model replies and tool-call diagnostics are persisted.
     #[serde(default = "default_runtime_trace_mode")]
@@ -1784,6 +1789,7 @@ impl Default for ObservabilityConfig {
             backend: "none".into(),
             otel_endpoint: None,
             otel_service_name: None,
+            webhook_url: None,
             runtime_trace_mode: default_runtime_trace_mode(),
             runtime_trace_path: default_runtime_trace_path(),
             runtime_trace_max_entries: default_runtime_trace_max_entries(),
@@ -4199,6 +4205,14 @@ impl Config {
                 }
             }
         }
+        // Observability webhook endpoint: ZEROCLAW_WEBHOOK_URL
+        if let Ok(url) = std::env::var("ZEROCLAW_WEBHOOK_URL") {
+            let url = url.trim();
+            if !url.is_empty() {
+                self.observability.webhook_url = Some(url.to_string());
+            }
+        }
+
         // Proxy enabled flag: ZEROCLAW_PROXY_ENABLED
         let explicit_proxy_enabled = std::env::var("ZEROCLAW_PROXY_ENABLED")
             .ok()
@@ -7,6 +7,7 @@ pub mod prometheus;
 pub mod runtime_trace;
 pub mod traits;
 pub mod verbose;
+pub mod webhook;
 
 #[allow(unused_imports)]
 pub use self::log::LogObserver;
@@ -19,6 +20,7 @@ pub use prometheus::PrometheusObserver;
 pub use traits::{Observer, ObserverEvent};
 #[allow(unused_imports)]
 pub use verbose::VerboseObserver;
+pub use webhook::WebhookObserver;
 
 use crate::config::ObservabilityConfig;
 
@@ -56,6 +58,20 @@ pub fn create_observer(config: &ObservabilityConfig) -> Box<dyn Observer> {
                 Box::new(NoopObserver)
             }
         }
+        "webhook" => match config
+            .webhook_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+        {
+            Some(url) => Box::new(WebhookObserver::new(url)),
+            None => {
+                tracing::warn!(
+                    "Webhook backend requested but no webhook_url / ZEROCLAW_WEBHOOK_URL is set; falling back to noop."
+                );
+                Box::new(NoopObserver)
+            }
+        },
         "none" | "noop" => Box::new(NoopObserver),
         _ => {
             tracing::warn!(
@@ -107,6 +123,26 @@ mod tests {
         assert_eq!(create_observer(&cfg).name(), "prometheus");
     }
 
+    #[test]
+    fn factory_webhook_returns_webhook() {
+        let cfg = ObservabilityConfig {
+            backend: "webhook".into(),
+            webhook_url: Some("http://127.0.0.1:9/events".into()),
+            ..ObservabilityConfig::default()
+        };
+        assert_eq!(create_observer(&cfg).name(), "webhook");
+    }
+
+    #[test]
+    fn factory_webhook_without_url_falls_back_to_noop() {
+        let cfg = ObservabilityConfig {
+            backend: "webhook".into(),
+            webhook_url: Some("   ".into()),
+            ..ObservabilityConfig::default()
+        };
+        assert_eq!(create_observer(&cfg).name(), "noop");
+    }
+
     #[test]
     fn factory_otel_returns_otel() {
         let cfg = ObservabilityConfig {
@@ -0,0 +1,296 @@
+use super::traits::{Observer, ObserverEvent, ObserverMetric};
+use serde_json::{json, Value};
+use std::any::Any;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::mpsc::{self, Receiver, SyncSender, TrySendError};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Maximum number of payloads buffered before new ones are dropped.
+const WEBHOOK_QUEUE_CAPACITY: usize = 256;
+/// Per-request timeout for a single webhook POST.
+const WEBHOOK_TIMEOUT_SECS: u64 = 10;
+/// Connect timeout for the webhook endpoint.
+const WEBHOOK_CONNECT_TIMEOUT_SECS: u64 = 5;
+
+/// Webhook observer — POSTs each event/metric as JSON to an HTTP endpoint.
+///
+/// Delivery is fire-and-forget: payloads go through a bounded queue drained by
+/// a dedicated worker thread, and are dropped (never blocked on) when the queue
+/// is full so a slow or unreachable endpoint cannot stall the agent loop.
+pub struct WebhookObserver {
+    sender: SyncSender<Value>,
+    dropped: AtomicU64,
+}
+
+impl WebhookObserver {
+    pub fn new(url: &str) -> Self {
+        let (sender, receiver) = mpsc::sync_channel(WEBHOOK_QUEUE_CAPACITY);
+        let url = url.to_string();
+        let spawned = std::thread::Builder::new()
+            .name("zeroclaw-webhook-observer".into())
+            .spawn(move || run_worker(&url, &receiver));
+        if let Err(e) = spawned {
+            tracing::warn!("Failed to start webhook observer worker: {e}");
+        }
+        Self {
+            sender,
+            dropped: AtomicU64::new(0),
+        }
+    }
+
+    /// Number of payloads dropped because the queue was full or the worker exited.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    fn enqueue(&self, payload: Value) {
+        match self.sender.try_send(payload) {
+            Ok(()) => {}
+            Err(TrySendError::Full(_) | TrySendError::Disconnected(_)) => {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+    }
+}
+
+fn run_worker(url: &str, receiver: &Receiver<Value>) {
+    let runtime = match tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+    {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            tracing::warn!("Webhook observer runtime init failed: {e}");
+            return;
+        }
+    };
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(WEBHOOK_TIMEOUT_SECS))
+        .connect_timeout(Duration::from_secs(WEBHOOK_CONNECT_TIMEOUT_SECS))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+
+    while let Ok(payload) = receiver.recv() {
+        let result = runtime.block_on(client.post(url).json(&payload).send());
+        match result {
+            Ok(response) if !response.status().is_success() => {
+                tracing::debug!(status = %response.status(), "webhook observer delivery rejected");
+            }
+            Ok(_) => {}
+            Err(e) => {
+                tracing::debug!("webhook observer delivery failed: {e}");
+            }
+        }
+    }
+}
+
+fn duration_ms(duration: &Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+fn timestamp_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| duration_ms(&d))
+        .unwrap_or(0)
+}
+
+/// Serialize an event into the webhook JSON payload.
+pub(crate) fn event_to_json(event: &ObserverEvent) -> Value {
+    let (name, data) = match event {
+        ObserverEvent::AgentStart { provider, model } => (
+            "agent.start",
+            json!({ "provider": provider, "model": model }),
+        ),
+        ObserverEvent::LlmRequest {
+            provider,
+            model,
+            messages_count,
+        } => (
+            "llm.request",
+            json!({
+                "provider": provider,
+                "model": model,
+                "messages_count": messages_count,
+            }),
+        ),
+        ObserverEvent::LlmResponse {
+            provider,
+            model,
+            duration,
+            success,
+            error_message,
+            input_tokens,
+            output_tokens,
+        } => (
+            "llm.response",
+            json!({
+                "provider": provider,
+                "model": model,
+                "duration_ms": duration_ms(duration),
+                "success": success,
+                "error": error_message,
+                "input_tokens": input_tokens,
+                "output_tokens": output_tokens,
+            }),
+        ),
+        ObserverEvent::AgentEnd {
+            provider,
+            model,
+            duration,
+            tokens_used,
+            cost_usd,
+        } => (
+            "agent.end",
+            json!({
+                "provider": provider,
+                "model": model,
+                "duration_ms": duration_ms(duration),
+                "tokens_used": tokens_used,
+                "cost_usd": cost_usd,
+            }),
+        ),
+        ObserverEvent::ToolCallStart { tool } => ("tool.start", json!({ "tool": tool })),
+        ObserverEvent::ToolCall {
+            tool,
+            duration,
+            success,
+        } => (
+            "tool.call",
+            json!({
+                "tool": tool,
+                "duration_ms": duration_ms(duration),
+                "success": success,
+            }),
+        ),
+        ObserverEvent::TurnComplete => ("turn.complete", json!({})),
+        ObserverEvent::ChannelMessage { channel, direction } => (
+            "channel.message",
+            json!({ "channel": channel, "direction": direction }),
+        ),
+        ObserverEvent::HeartbeatTick => ("heartbeat.tick", json!({})),
+        ObserverEvent::Error { component, message } => (
+            "error",
+            json!({ "component": component, "message": message }),
+        ),
+    };
+    json!({
+        "kind": "event",
+        "name": name,
+        "timestamp_ms": timestamp_ms(),
+        "data": data,
+    })
+}
+
+/// Serialize a metric sample into the webhook JSON payload.
+pub(crate) fn metric_to_json(metric: &ObserverMetric) -> Value {
+    let (name, value) = match metric {
+        ObserverMetric::RequestLatency(d) => ("request_latency_ms", duration_ms(d)),
+        ObserverMetric::TokensUsed(t) => ("tokens_used", *t),
+        ObserverMetric::ActiveSessions(s) => ("active_sessions", *s),
+        ObserverMetric::QueueDepth(d) => ("queue_depth", *d),
+    };
+    json!({
+        "kind": "metric",
+        "name": name,
+        "timestamp_ms": timestamp_ms(),
+        "value": value,
+    })
+}
+
+impl Observer for WebhookObserver {
+    fn record_event(&self, event: &ObserverEvent) {
+        self.enqueue(event_to_json(event));
+    }
+
+    fn record_metric(&self, metric: &ObserverMetric) {
+        self.enqueue(metric_to_json(metric));
+    }
+
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Nothing listens on port 9 (discard); deliveries fail fast and are ignored.
+    const UNREACHABLE_URL: &str = "http://127.0.0.1:9/webhook";
+
+    #[test]
+    fn webhook_observer_name() {
+        assert_eq!(WebhookObserver::new(UNREACHABLE_URL).name(), "webhook");
+    }
+
+    #[test]
+    fn events_serialize_with_name_and_data() {
+        let payload = event_to_json(&ObserverEvent::ToolCall {
+            tool: "shell".into(),
+            duration: Duration::from_millis(42),
+            success: false,
+        });
+        assert_eq!(payload["kind"], "event");
+        assert_eq!(payload["name"], "tool.call");
+        assert_eq!(payload["data"]["tool"], "shell");
+        assert_eq!(payload["data"]["duration_ms"], 42);
+        assert_eq!(payload["data"]["success"], false);
+
+        let payload = event_to_json(&ObserverEvent::LlmResponse {
+            provider: "openrouter".into(),
+            model: "claude-sonnet".into(),
+            duration: Duration::MAX,
+            success: false,
+            error_message: Some("rate limited".into()),
+            input_tokens: None,
+            output_tokens: Some(7),
+        });
+        assert_eq!(payload["data"]["duration_ms"], u64::MAX);
+        assert_eq!(payload["data"]["error"], "rate limited");
+        assert!(payload["data"]["input_tokens"].is_null());
+    }
+
+    #[test]
+    fn metrics_serialize_with_name_and_value() {
+        let payload = metric_to_json(&ObserverMetric::TokensUsed(500));
+        assert_eq!(payload["kind"], "metric");
+        assert_eq!(payload["name"], "tokens_used");
+        assert_eq!(payload["value"], 500);
+
+        let payload = metric_to_json(&ObserverMetric::RequestLatency(Duration::from_secs(2)));
+        assert_eq!(payload["name"], "request_latency_ms");
+        assert_eq!(payload["value"], 2000);
+    }
+
+    #[test]
+    fn full_queue_drops_instead_of_blocking() {
+        // Hold the receiver without draining it to simulate a stalled worker.
+        let (sender, _receiver) = mpsc::sync_channel(1);
+        let obs = WebhookObserver {
+            sender,
+            dropped: AtomicU64::new(0),
+        };
+        for _ in 0..32 {
+            obs.record_event(&ObserverEvent::HeartbeatTick);
+            obs.record_metric(&ObserverMetric::QueueDepth(1));
+        }
+        assert_eq!(obs.dropped_count(), 63);
+    }
+
+    #[test]
+    fn disconnected_worker_drops_instead_of_panicking() {
+        let (sender, receiver) = mpsc::sync_channel(4);
+        drop(receiver);
+        let obs = WebhookObserver {
+            sender,
+            dropped: AtomicU64::new(0),
+        };
+        obs.record_event(&ObserverEvent::TurnComplete);
+        assert_eq!(obs.dropped_count(), 1);
+    }
+}